@@ -17,6 +17,48 @@
 #include <stdlib.h>
 
 
+/**
+ * Parses an address and returns its network, type, public keys, and payment ID as JSON.
+ *
+ * Returns a null pointer if the address is invalid.
+ *
+ * # Safety
+ *
+ * `addr` must be a valid NUL-terminated string.
+ *
+ * The returned string is owned by the caller and must be released with [`free_string`].
+ */
+char *address_parse(const char *addr);
+
+/**
+ * Decodes a hex-encoded transaction and returns its summary as JSON.
+ *
+ * Returns a null pointer if the transaction is invalid.
+ *
+ * # Safety
+ *
+ * `tx_hex` must be a valid NUL-terminated string.
+ *
+ * The returned string is owned by the caller and must be released with [`free_string`].
+ */
+char *decode_transaction(const char *tx_hex);
+
+/**
+ * Formats an amount in atomic units as a decimal XMR string.
+ *
+ * The returned string is owned by the caller and must be released with [`free_string`].
+ */
+char *format_amount(uint64_t atomic);
+
+/**
+ * Releases a string returned by one of this library's functions.
+ *
+ * # Safety
+ *
+ * `s` must be null or a pointer returned by this library that has not already been freed.
+ */
+void free_string(char *s);
+
 /**
  * Generates an address from a mnemonic
  */
@@ -25,7 +67,81 @@ const char *generate_address(const char *mnemonic,
                              uint32_t account,
                              uint32_t index);
 
+/**
+ * Generates the primary address for a hex-encoded private spend key.
+ *
+ * Returns a null pointer if any argument is invalid.
+ *
+ * # Safety
+ *
+ * `spend_key` must be a valid NUL-terminated string.
+ *
+ * The returned string is owned by the caller and must be released with [`free_string`].
+ */
+char *generate_address_from_spend_key(const char *spend_key, uint8_t network);
+
+/**
+ * Generates an integrated address from a mnemonic and an 8-byte payment ID.
+ *
+ * Returns a null pointer if any argument is invalid.
+ *
+ * # Safety
+ *
+ * `mnemonic` must be a valid NUL-terminated string and `payment_id` must point to 8 readable
+ * bytes.
+ *
+ * The returned string is owned by the caller and must be released with [`free_string`].
+ */
+char *generate_integrated_address(const char *mnemonic, uint8_t network, const uint8_t *payment_id);
+
 /**
  * Generates a mnemonic in the specified language
  */
 const char *generate_mnemonic(uint8_t language);
+
+/**
+ * Generates a `monero:` payment URI from a mnemonic for the given account and index.
+ *
+ * An `amount` of 0 omits the amount, and a null `description` omits the description. Returns a
+ * null pointer if any argument is invalid.
+ *
+ * # Safety
+ *
+ * `mnemonic` must be a valid NUL-terminated string, and `description` must be null or a valid
+ * NUL-terminated string.
+ *
+ * The returned string is owned by the caller and must be released with [`free_string`].
+ */
+char *generate_payment_uri(const char *mnemonic,
+                           uint8_t network,
+                           uint32_t account,
+                           uint32_t index,
+                           uint64_t amount,
+                           const char *description);
+
+/**
+ * Derives the public spend and view keys of a subaddress from a mnemonic, returned as JSON.
+ *
+ * Returns a null pointer if any argument is invalid.
+ *
+ * # Safety
+ *
+ * `mnemonic` must be a valid NUL-terminated string.
+ *
+ * The returned string is owned by the caller and must be released with [`free_string`].
+ */
+char *generate_subaddress_keys(const char *mnemonic,
+                               uint8_t network,
+                               uint32_t account,
+                               uint32_t index);
+
+/**
+ * Parses a decimal XMR string into atomic units, writing the result to `atomic`.
+ *
+ * Returns false, leaving `atomic` untouched, if the amount is invalid.
+ *
+ * # Safety
+ *
+ * `amount` must be a valid NUL-terminated string and `atomic` must be a valid, writable pointer.
+ */
+bool parse_amount(const char *amount, uint64_t *atomic);
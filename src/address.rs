@@ -0,0 +1,139 @@
+use monero_serai_mirror::wallet::address::{AddressBytes, AddressType, MoneroAddress, MoneroAddressBytes, Network};
+
+// A standard address (1 + 32 + 32 + 4 checksum bytes) encodes to 95 characters.
+const MIN_ADDRESS_LENGTH: usize = 95;
+
+// Valid lengths of the final base58 block, indexed by the number of bytes it encodes.
+const ENCODED_BLOCK_SIZES: [usize; 9] = [0, 2, 3, 5, 6, 7, 9, 10, 11];
+
+/// The kind of a parsed Monero address.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AddressKind {
+    Legacy,
+    Subaddress,
+    Integrated,
+}
+
+/// The components of a decoded Monero address.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParsedAddress {
+    pub network: Network,
+    pub kind: AddressKind,
+    pub spend_public: [u8; 32],
+    pub view_public: [u8; 32],
+    pub payment_id: Option<[u8; 8]>,
+}
+
+impl ParsedAddress {
+    /// Serializes the parsed address as a JSON object with hex-encoded keys.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "network": crate::network_to_str(self.network),
+            "kind": match self.kind {
+                AddressKind::Legacy => "legacy",
+                AddressKind::Subaddress => "subaddress",
+                AddressKind::Integrated => "integrated",
+            },
+            "spend_public": hex::encode(self.spend_public),
+            "view_public": hex::encode(self.view_public),
+            "payment_id": self.payment_id.map(hex::encode),
+        })
+    }
+}
+
+/// Parses a Monero address string, returning its network, type, keys, and payment ID.
+///
+/// # Arguments
+///
+/// * `addr` - The address to parse.
+///
+/// # Errors
+///
+/// Returns an error if the address is malformed or uses an unsupported (featured) encoding.
+///
+/// # Example
+///
+/// ```
+/// use monero_rust::address::{parse_address, AddressKind};
+/// let parsed = parse_address("45wsWad9EwZgF3VpxQumrUCRaEtdyyh6NG8sVD3YRVVJbK1jkpJ3zq8WHLijVzodQ22LxwkdWx7fS2a6JzaRGzkNU8K2Dhi").unwrap();
+/// assert_eq!(parsed.kind, AddressKind::Legacy);
+/// ```
+pub fn parse_address(addr: &str) -> Result<ParsedAddress, String> {
+    let addr = addr.trim();
+    // base58-monero panics on inputs shorter than its checksum, so reject those up front.
+    if addr.len() < MIN_ADDRESS_LENGTH || !ENCODED_BLOCK_SIZES.contains(&(addr.len() % 11)) {
+        return Err("invalid address encoding".to_string());
+    }
+    // from_str_raw indexes past the keys of a featured address without checking its length, so
+    // featured prefixes are rejected before handing it the string.
+    let raw = base58_monero::decode_check(addr).map_err(|_| "invalid address encoding".to_string())?;
+    let featured = [Network::Mainnet, Network::Testnet, Network::Stagenet]
+        .map(|network| MoneroAddressBytes::network_bytes(network).3);
+    if raw.first().is_some_and(|byte| featured.contains(byte)) {
+        return Err("Unsupported address type".to_string());
+    }
+    let address = MoneroAddress::from_str_raw(addr).map_err(|e| e.to_string())?;
+    let kind = match address.meta.kind {
+        AddressType::Standard => AddressKind::Legacy,
+        AddressType::Subaddress => AddressKind::Subaddress,
+        AddressType::Integrated(_) => AddressKind::Integrated,
+        AddressType::Featured { .. } => return Err("Unsupported address type".to_string()),
+    };
+
+    Ok(ParsedAddress {
+        network: address.network(),
+        kind,
+        spend_public: address.spend.compress().to_bytes(),
+        view_public: address.view.compress().to_bytes(),
+        payment_id: address.payment_id(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MoneroWallet;
+
+    // https://xmrtests.llcoins.net/addresstests.html
+    const ADDRESS: &str = "45wsWad9EwZgF3VpxQumrUCRaEtdyyh6NG8sVD3YRVVJbK1jkpJ3zq8WHLijVzodQ22LxwkdWx7fS2a6JzaRGzkNU8K2Dhi";
+    const SUBADDRESS: &str = "86QMPxju4EHGHZfyswVHXsQcKK3vJgqUFgbP8Xx8DNTSjaGqcp8KXc9isQS3Hh8twz8huegagK19rJLDbBwCwAxRHX4vcv5";
+    const MNEMONIC: &str = "hemlock jubilee eden hacksaw boil superior inroads epoxy exhale orders cavernous second brunt saved richly lower upgrade hitched launching deepest mostly playful layout lower eden";
+
+    #[test]
+    fn test_parse_standard_address() {
+        let parsed = parse_address(ADDRESS).expect("Failed to parse address");
+        assert_eq!(parsed.network, Network::Mainnet);
+        assert_eq!(parsed.kind, AddressKind::Legacy);
+        assert_eq!(hex::encode(parsed.spend_public), "72170da1793490ea9d0243df46c515444c35104b92b1d75a7d8c5954ba1f49cd");
+        assert_eq!(hex::encode(parsed.view_public), "21243cb8d0046baf10619d1fe7f38708095b006ef8e8350963c160478c1c0ff0");
+        assert_eq!(parsed.payment_id, None);
+    }
+
+    #[test]
+    fn test_parse_subaddress() {
+        let parsed = parse_address(SUBADDRESS).expect("Failed to parse subaddress");
+        assert_eq!(parsed.network, Network::Mainnet);
+        assert_eq!(parsed.kind, AddressKind::Subaddress);
+    }
+
+    #[test]
+    fn test_parse_integrated_address() {
+        let wallet = MoneroWallet::new(MNEMONIC, Network::Mainnet).expect("Failed to create wallet");
+        let payment_id = [0xde, 0xad, 0xbe, 0xef, 0x00, 0x11, 0x22, 0x33];
+        let integrated = wallet.get_integrated_address(payment_id).expect("Failed to get integrated address");
+        let parsed = parse_address(&integrated).expect("Failed to parse integrated address");
+        assert_eq!(parsed.kind, AddressKind::Integrated);
+        assert_eq!(parsed.payment_id, Some(payment_id));
+        assert_eq!(parsed.spend_public, parse_address(ADDRESS).unwrap().spend_public);
+    }
+
+    #[test]
+    fn test_parse_invalid_address() {
+        assert!(parse_address("").is_err());
+        assert!(parse_address(&ADDRESS[.. ADDRESS.len() - 1]).is_err());
+        assert!(parse_address(&ADDRESS.replace('w', "x")).is_err());
+        // A mainnet featured prefix (70) followed by two basepoints, with no feature byte.
+        let featured = "CmSPFeLABHfJ8QgRfFWTzmJ8QgRfFWTzmJ8QgRfFWTzmJ7suhUXwdrDJ8QgRfFWTzmJ8QgRfFWTzmJ8QgRfFWTzmCZxtFy1";
+        assert_eq!(parse_address(featured), Err("Unsupported address type".to_string()));
+    }
+}
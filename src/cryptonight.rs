@@ -0,0 +1,187 @@
+//! The original CryptoNight slow hash (`cn_slow_hash` variant 0), used by Monero to stretch seed
+//! passphrases.
+
+use aes::{hazmat::cipher_round, Block};
+use groestl::Groestl256;
+use jh::Jh256;
+use skein::{consts::U32, Digest, Skein512};
+
+// The 2 MiB scratchpad, in AES blocks.
+const SCRATCHPAD_BLOCKS: usize = (1 << 21) / 16;
+// Each iteration of the main loop performs two of the reference implementation's steps.
+const ITERATIONS: usize = 1 << 19;
+// The Keccak rate used by Monero's `keccak1600`.
+const KECCAK_RATE: usize = 136;
+
+/// Hashes `data` with CryptoNight, as Monero's `cn_slow_hash(data, len, hash, 0, 0)`.
+pub(crate) fn cn_slow_hash(data: &[u8]) -> [u8; 32] {
+    let mut state = state_bytes(&keccak1600(data));
+
+    let mut text = init_blocks(&state);
+    let round_keys = expand_key(&state[.. 32]);
+    let mut scratchpad = vec![Block::default(); SCRATCHPAD_BLOCKS];
+    for chunk in scratchpad.chunks_exact_mut(text.len()) {
+        for block in text.iter_mut() {
+            round_keys.iter().for_each(|key| cipher_round(block, key));
+        }
+        chunk.copy_from_slice(&text);
+    }
+
+    let mut a = xor(Block::from_slice(&state[.. 16]), Block::from_slice(&state[32 .. 48]));
+    let mut b = xor(Block::from_slice(&state[16 .. 32]), Block::from_slice(&state[48 .. 64]));
+    for _ in 0 .. ITERATIONS {
+        let j = scratchpad_index(&a);
+        let mut c = scratchpad[j];
+        cipher_round(&mut c, &a);
+        scratchpad[j] = xor(&b, &c);
+
+        let k = scratchpad_index(&c);
+        let d = scratchpad[k];
+        let product = u128::from(low_u64(&c)) * u128::from(low_u64(&d));
+        let mut sum = Block::default();
+        sum[.. 8].copy_from_slice(&low_u64(&a).wrapping_add((product >> 64) as u64).to_le_bytes());
+        sum[8 ..].copy_from_slice(&high_u64(&a).wrapping_add(product as u64).to_le_bytes());
+        scratchpad[k] = sum;
+        a = xor(&sum, &d);
+        b = c;
+    }
+
+    let mut text = init_blocks(&state);
+    let round_keys = expand_key(&state[32 .. 64]);
+    for chunk in scratchpad.chunks_exact(text.len()) {
+        for (block, scratch) in text.iter_mut().zip(chunk) {
+            *block = xor(block, scratch);
+            round_keys.iter().for_each(|key| cipher_round(block, key));
+        }
+    }
+    for (bytes, block) in state[64 .. 192].chunks_exact_mut(16).zip(&text) {
+        bytes.copy_from_slice(block);
+    }
+
+    let mut lanes = [0u64; 25];
+    absorb(&mut lanes, &state);
+    keccak::f1600(&mut lanes);
+    state = state_bytes(&lanes);
+
+    match state[0] & 3 {
+        0 => <blake_hash::Blake256 as blake_hash::Digest>::digest(&state).into(),
+        1 => Groestl256::digest(state).into(),
+        2 => Jh256::digest(state).into(),
+        _ => Skein512::<U32>::digest(state).into(),
+    }
+}
+
+// Keccak-f[1600] with Keccak's original padding, returning the whole state.
+fn keccak1600(data: &[u8]) -> [u64; 25] {
+    let mut lanes = [0u64; 25];
+    let mut chunks = data.chunks_exact(KECCAK_RATE);
+    for chunk in &mut chunks {
+        absorb(&mut lanes, chunk);
+        keccak::f1600(&mut lanes);
+    }
+    let remainder = chunks.remainder();
+    let mut last = [0u8; KECCAK_RATE];
+    last[.. remainder.len()].copy_from_slice(remainder);
+    last[remainder.len()] = 1;
+    last[KECCAK_RATE - 1] |= 0x80;
+    absorb(&mut lanes, &last);
+    keccak::f1600(&mut lanes);
+    lanes
+}
+
+fn absorb(lanes: &mut [u64; 25], bytes: &[u8]) {
+    for (lane, bytes) in lanes.iter_mut().zip(bytes.chunks_exact(8)) {
+        *lane ^= u64::from_le_bytes(bytes.try_into().unwrap());
+    }
+}
+
+fn state_bytes(lanes: &[u64; 25]) -> [u8; 200] {
+    let mut bytes = [0u8; 200];
+    for (bytes, lane) in bytes.chunks_exact_mut(8).zip(lanes) {
+        bytes.copy_from_slice(&lane.to_le_bytes());
+    }
+    bytes
+}
+
+fn init_blocks(state: &[u8; 200]) -> [Block; 8] {
+    let mut blocks = [Block::default(); 8];
+    for (block, bytes) in blocks.iter_mut().zip(state[64 .. 192].chunks_exact(16)) {
+        block.copy_from_slice(bytes);
+    }
+    blocks
+}
+
+// The first 10 round keys of the AES-256 key schedule, which CryptoNight uses as 10 full rounds.
+fn expand_key(key: &[u8]) -> [Block; 10] {
+    let mut words = [[0u8; 4]; 40];
+    for (word, bytes) in words.iter_mut().zip(key.chunks_exact(4)) {
+        word.copy_from_slice(bytes);
+    }
+    let mut rcon = 1;
+    for i in 8 .. words.len() {
+        let mut word = words[i - 1];
+        if i % 8 == 0 {
+            word.rotate_left(1);
+            word = word.map(sub_byte);
+            word[0] ^= rcon;
+            rcon <<= 1;
+        } else if i % 8 == 4 {
+            word = word.map(sub_byte);
+        }
+        for (byte, previous) in word.iter_mut().zip(words[i - 8]) {
+            *byte ^= previous;
+        }
+        words[i] = word;
+    }
+
+    let mut round_keys = [Block::default(); 10];
+    for (round_key, words) in round_keys.iter_mut().zip(words.chunks_exact(4)) {
+        round_key.copy_from_slice(&words.concat());
+    }
+    round_keys
+}
+
+// ShiftRows and MixColumns leave a state of identical bytes unchanged, so a round with a zero key
+// applies only SubBytes.
+fn sub_byte(byte: u8) -> u8 {
+    let mut block = Block::from([byte; 16]);
+    cipher_round(&mut block, &Block::default());
+    block[0]
+}
+
+fn scratchpad_index(block: &Block) -> usize {
+    ((low_u64(block) >> 4) % SCRATCHPAD_BLOCKS as u64) as usize
+}
+
+fn low_u64(block: &Block) -> u64 {
+    u64::from_le_bytes(block[.. 8].try_into().unwrap())
+}
+
+fn high_u64(block: &Block) -> u64 {
+    u64::from_le_bytes(block[8 ..].try_into().unwrap())
+}
+
+fn xor(a: &Block, b: &Block) -> Block {
+    a.iter().zip(b).map(|(a, b)| a ^ b).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cn_slow_hash() {
+        // Reference vectors for Monero's cn_slow_hash
+        let vectors = [
+            ("", "eb14e8a833fac6fe9a43b57b336789c46ffe93f2868452240720607b14387e11"),
+            ("This is a test", "a084f01d1437a09c6985401b60d43554ae105802c5f5d8a9b3253649c0be6605"),
+            ("de omnibus dubitandum", "2f8e3df40bd11f9ac90c743ca8e32bb391da4fb98612aa3b6cdc639ee00b31f5"),
+            ("abundans cautela non nocet", "722fa8ccd594d40e4a41f3822734304c8d5eff7e1b528408e2229da38ba553c4"),
+            ("caveat emptor", "bbec2cacf69866a8e740380fe7b818fc78f8571221742d729d9d02d7f8989b87"),
+            ("ex nihilo nihil fit", "b1257de4efc5ce28c6b40ceb1c6c8f812a64634eb3e81c5220bee9b2b76a6f05"),
+        ];
+        for (input, hash) in vectors {
+            assert_eq!(hex::encode(cn_slow_hash(input.as_bytes())), hash, "{:?}", input);
+        }
+    }
+}
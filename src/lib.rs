@@ -6,11 +6,16 @@ use monero_serai_mirror::{
     },
 };
 
+pub mod address;
+mod cryptonight;
+pub mod tx;
+pub mod uri;
+
 // Re-export for tests.
 pub use monero_serai_mirror::wallet::seed::Language;
 pub use monero_serai_mirror::wallet::address::Network;
 
-use rand_core::OsRng;
+use rand_core::{OsRng, RngCore};
 use zeroize::{Zeroizing};
 use curve25519_dalek::{
     edwards::EdwardsPoint,
@@ -19,8 +24,13 @@ use curve25519_dalek::{
 };
 use sha3::{Digest, Keccak256};
 
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+
 pub struct MoneroWallet {
-    seed: Seed,
+    seed: Option<Seed>,
+    spend_key: Zeroizing<[u8; 32]>,
+    has_passphrase: bool,
     view_pair: ViewPair,
     network: Network,
 }
@@ -45,19 +55,102 @@ impl MoneroWallet {
     /// let wallet = MoneroWallet::new(&mnemonic, Network::Mainnet).unwrap();
     /// ```
     pub fn new(mnemonic: &str, network: Network) -> Result<Self, String> {
+        Self::new_with_passphrase(mnemonic, "", network)
+    }
+
+    /// Creates a new MoneroWallet from a mnemonic, a seed passphrase, and network type.
+    ///
+    /// A non-empty passphrase is applied as monero-wallet-cli applies a seed passphrase: its
+    /// CryptoNight hash is subtracted from the seed's private spend key. The same mnemonic and
+    /// passphrase therefore restore the same wallet as the official CLI and GUI. An empty
+    /// passphrase yields the same keys as [`MoneroWallet::new`].
+    ///
+    /// # Arguments
+    ///
+    /// * `mnemonic` - A string slice that holds the mnemonic seed phrase.
+    /// * `passphrase` - The seed passphrase (the "25th word"), or `""` for none.
+    /// * `network` - The Monero network type (Mainnet, Testnet, or Stagenet).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mnemonic is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use monero_rust::{MoneroWallet, Language, Network};
+    /// let mnemonic = MoneroWallet::generate_mnemonic(Language::English);
+    /// let wallet = MoneroWallet::new_with_passphrase(&mnemonic, "hunter2", Network::Mainnet).unwrap();
+    /// assert!(wallet.has_passphrase());
+    /// ```
+    pub fn new_with_passphrase(mnemonic: &str, passphrase: &str, network: Network) -> Result<Self, String> {
         let seed = Seed::from_string(Zeroizing::new(mnemonic.to_string())).map_err(|_| "Invalid mnemonic".to_string())?;
-        let spend: [u8; 32] = *seed.entropy();
-        let spend_scalar: Scalar = Scalar::from_bytes_mod_order(spend);
+        let has_passphrase = !passphrase.is_empty();
+        let spend_key: Zeroizing<[u8; 32]> = if has_passphrase {
+            let offset = Zeroizing::new(cryptonight::cn_slow_hash(passphrase.as_bytes()));
+            let offset_scalar = Zeroizing::new(Scalar::from_bytes_mod_order(*offset));
+            let seed_scalar = Zeroizing::new(Scalar::from_bytes_mod_order(*seed.entropy()));
+            Zeroizing::new((*seed_scalar - *offset_scalar).to_bytes())
+        } else {
+            seed.entropy()
+        };
+        Ok(Self::from_spend_key(Some(seed), spend_key, has_passphrase, network))
+    }
+
+    /// Creates a new MoneroWallet from a hex-encoded private spend key and network type.
+    ///
+    /// The private view key is derived from the spend key as usual, so the wallet can spend and
+    /// matches the wallet restored from the equivalent mnemonic. It has no mnemonic of its own,
+    /// so [`MoneroWallet::get_seed`] returns `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `spend_key_hex` - The 32-byte private spend key in hexadecimal format.
+    /// * `network` - The Monero network type (Mainnet, Testnet, or Stagenet).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key is not 32 bytes of hex or is not a canonical, non-zero scalar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use monero_rust::{MoneroWallet, Network};
+    /// let wallet = MoneroWallet::new_from_spend_key("29adefc8f67515b4b4bf48031780ab9d071d24f8a674b879ce7f245c37523807", Network::Mainnet).unwrap();
+    /// assert!(wallet.get_seed().is_none());
+    /// ```
+    pub fn new_from_spend_key(spend_key_hex: &str, network: Network) -> Result<Self, String> {
+        let mut spend_key = Zeroizing::new([0u8; 32]);
+        hex::decode_to_slice(spend_key_hex.trim(), spend_key.as_mut()).map_err(|_| "Invalid spend key".to_string())?;
+        match Scalar::from_canonical_bytes(*spend_key) {
+            Some(scalar) if scalar != Scalar::zero() => {}
+            _ => return Err("Invalid spend key".to_string()),
+        }
+        Ok(Self::from_spend_key(None, spend_key, false, network))
+    }
+
+    fn from_spend_key(seed: Option<Seed>, spend_key: Zeroizing<[u8; 32]>, has_passphrase: bool, network: Network) -> Self {
+        let spend_scalar: Scalar = Scalar::from_bytes_mod_order(*spend_key);
         let spend_point: EdwardsPoint = &spend_scalar * &ED25519_BASEPOINT_TABLE;
-        let view: [u8; 32] = Keccak256::digest(&spend).into();
+        let view: [u8; 32] = Keccak256::digest(*spend_key).into();
         let view_scalar: Scalar = Scalar::from_bytes_mod_order(view);
         let view_pair = ViewPair::new(spend_point, Zeroizing::new(view_scalar));
 
-        Ok(MoneroWallet {
+        MoneroWallet {
             seed,
+            spend_key,
+            has_passphrase,
             view_pair,
             network,
-        })
+        }
+    }
+
+    /// Returns whether the wallet's keys were offset by a seed passphrase.
+    ///
+    /// Callers persisting the wallet should store this so they know to prompt for the
+    /// passphrase when restoring from the mnemonic.
+    pub fn has_passphrase(&self) -> bool {
+        self.has_passphrase
     }
 
     /// Generates a new mnemonic seed in the specified language.
@@ -84,9 +177,10 @@ impl MoneroWallet {
     ///
     /// # Returns
     ///
-    /// A `String` representing the mnemonic seed.
-    pub fn get_seed(&self) -> String {
-        Seed::to_string(&self.seed).to_string()
+    /// A `String` representing the mnemonic seed, or `None` if the wallet was created from a
+    /// raw spend key.
+    pub fn get_seed(&self) -> Option<String> {
+        self.seed.as_ref().map(|seed| Seed::to_string(seed).to_string())
     }
 
     /// Returns the primary address of the wallet.
@@ -121,13 +215,108 @@ impl MoneroWallet {
         Ok(address.to_string())
     }
 
+    /// Returns the public spend and view keys of the subaddress for the given account and index.
+    ///
+    /// # Arguments
+    ///
+    /// * `account` - The account index.
+    /// * `index` - The subaddress index.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the public spend key and public view key in hexadecimal format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subaddress index is invalid.
+    pub fn get_subaddress_keys(&self, account: u32, index: u32) -> Result<(String, String), String> {
+        let subaddress_index = SubaddressIndex::new(account, index).ok_or("Invalid subaddress index".to_string())?;
+        let address = self.view_pair.address(self.network, AddressSpec::Subaddress(subaddress_index));
+        Ok((hex::encode(address.spend.compress().to_bytes()), hex::encode(address.view.compress().to_bytes())))
+    }
+
+    /// Returns an integrated address embedding the given payment ID.
+    ///
+    /// Integrated addresses are only defined for the primary address; subaddresses cannot carry
+    /// a payment ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `payment_id` - The 8-byte payment ID to embed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the encoded address does not decode back to the same payment ID.
+    pub fn get_integrated_address(&self, payment_id: [u8; 8]) -> Result<String, String> {
+        let address = self.view_pair.address(self.network, AddressSpec::Integrated(payment_id));
+        let encoded = address.to_string();
+
+        // Round-trip the encoding so a subaddress or mangled ID can never be handed out.
+        let decoded = MoneroAddress::from_str(self.network, &encoded).map_err(|e| e.to_string())?;
+        if decoded.is_subaddress() || decoded.payment_id() != Some(payment_id) {
+            return Err("Invalid integrated address".to_string());
+        }
+        Ok(encoded)
+    }
+
+    /// Returns a `monero:` payment URI for the given account and subaddress index.
+    ///
+    /// Index (0, 0) refers to the primary address.
+    ///
+    /// # Arguments
+    ///
+    /// * `account` - The account index.
+    /// * `index` - The subaddress index.
+    /// * `amount` - The requested amount in atomic units, if any.
+    /// * `description` - A description of the payment, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subaddress index is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use monero_rust::{MoneroWallet, Language, Network};
+    /// let mnemonic = MoneroWallet::generate_mnemonic(Language::English);
+    /// let wallet = MoneroWallet::new(&mnemonic, Network::Mainnet).unwrap();
+    /// let uri = wallet.get_payment_uri(0, 0, Some(1_500_000_000_000), Some("Coffee")).unwrap();
+    /// assert!(uri.ends_with("?tx_amount=1.5&tx_description=Coffee"));
+    /// ```
+    pub fn get_payment_uri(&self, account: u32, index: u32, amount: Option<u64>, description: Option<&str>) -> Result<String, String> {
+        let address = if (account, index) == (0, 0) {
+            self.get_primary_address()
+        } else {
+            self.get_subaddress(account, index)?
+        };
+        let payment = uri::PaymentUri {
+            address,
+            payment_id: None,
+            amount,
+            description: description.map(str::to_string),
+            recipient_name: None,
+        };
+        Ok(payment.to_uri())
+    }
+
+    /// Generates a random 8-byte payment ID suitable for an integrated address.
+    ///
+    /// # Returns
+    ///
+    /// An `[u8; 8]` payment ID.
+    pub fn generate_random_payment_id() -> [u8; 8] {
+        let mut payment_id = [0u8; 8];
+        OsRng.fill_bytes(&mut payment_id);
+        payment_id
+    }
+
     /// Returns the private spend key of the wallet.
     ///
     /// # Returns
     ///
     /// A `String` representing the private spend key in hexadecimal format.
     pub fn get_private_spend_key(&self) -> String {
-        hex::encode(self.seed.entropy())
+        hex::encode(*self.spend_key)
     }
 
     /// Returns the private view key of the wallet.
@@ -136,7 +325,7 @@ impl MoneroWallet {
     ///
     /// A `String` representing the private view key in hexadecimal format.
     pub fn get_private_view_key(&self) -> String {
-        let view: [u8; 32] = Keccak256::digest(self.seed.entropy()).into();
+        let view: [u8; 32] = Keccak256::digest(*self.spend_key).into();
         hex::encode(view)
     }
 
@@ -161,6 +350,333 @@ impl MoneroWallet {
     }
 }
 
+/// Parses a network name (`mainnet`, `testnet`, or `stagenet`, case-insensitive).
+///
+/// # Errors
+///
+/// Returns an error if the name is not a known network.
+pub fn network_from_str(s: &str) -> Result<Network, String> {
+    match s.trim().to_ascii_lowercase().as_str() {
+        "mainnet" => Ok(Network::Mainnet),
+        "testnet" => Ok(Network::Testnet),
+        "stagenet" => Ok(Network::Stagenet),
+        _ => Err(format!("Unknown network: {}", s)),
+    }
+}
+
+/// Returns the lowercase name of a network, as accepted by [`network_from_str`].
+pub fn network_to_str(network: Network) -> &'static str {
+    match network {
+        Network::Mainnet => "mainnet",
+        Network::Testnet => "testnet",
+        Network::Stagenet => "stagenet",
+    }
+}
+
+/// Returns the network an address belongs to, based on its prefix byte.
+///
+/// Returns `None` if the address cannot be decoded.
+pub fn detect_network_from_address(addr: &str) -> Option<Network> {
+    address::parse_address(addr).ok().map(|parsed| parsed.network)
+}
+
+// Atomic units (piconero) per XMR.
+const ATOMIC_UNITS_PER_XMR: u64 = 1_000_000_000_000;
+const XMR_DECIMALS: usize = 12;
+
+/// Formats an amount in atomic units (piconero) as a decimal XMR string.
+///
+/// Trailing zeros are trimmed, so 1.5 XMR formats as `1.5` and 2 XMR as `2`.
+///
+/// # Example
+///
+/// ```
+/// use monero_rust::atomic_to_xmr_string;
+/// assert_eq!(atomic_to_xmr_string(1_500_000_000_000), "1.5");
+/// ```
+pub fn atomic_to_xmr_string(atomic: u64) -> String {
+    let whole = atomic / ATOMIC_UNITS_PER_XMR;
+    let fraction = atomic % ATOMIC_UNITS_PER_XMR;
+    if fraction == 0 {
+        return whole.to_string();
+    }
+    let fraction = format!("{:0width$}", fraction, width = XMR_DECIMALS);
+    format!("{}.{}", whole, fraction.trim_end_matches('0'))
+}
+
+/// Parses a decimal XMR string into atomic units (piconero) without floating point.
+///
+/// # Errors
+///
+/// Returns an error if the string is not a non-negative decimal number, has more than 12
+/// decimal places, or does not fit in a `u64`.
+///
+/// # Example
+///
+/// ```
+/// use monero_rust::xmr_string_to_atomic;
+/// assert_eq!(xmr_string_to_atomic("0.000000000001"), Ok(1));
+/// ```
+pub fn xmr_string_to_atomic(s: &str) -> Result<u64, String> {
+    let invalid = || format!("Invalid amount: {}", s);
+    let (whole, fraction) = s.trim().split_once('.').unwrap_or((s.trim(), ""));
+    if (whole.is_empty() && fraction.is_empty()) || fraction.len() > XMR_DECIMALS {
+        return Err(invalid());
+    }
+    if !whole.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let whole: u64 = if whole.is_empty() { 0 } else { whole.parse().map_err(|_| invalid())? };
+    let fraction: u64 = format!("{:0<width$}", fraction, width = XMR_DECIMALS).parse().map_err(|_| invalid())?;
+    whole.checked_mul(ATOMIC_UNITS_PER_XMR).and_then(|whole| whole.checked_add(fraction)).ok_or_else(invalid)
+}
+
+fn network_from_u8(network: u8) -> Option<Network> {
+    match network {
+        0 => Some(Network::Mainnet),
+        1 => Some(Network::Testnet),
+        2 => Some(Network::Stagenet),
+        _ => None,
+    }
+}
+
+/// Generates an integrated address from a mnemonic and an 8-byte payment ID.
+///
+/// Returns a null pointer if any argument is invalid.
+///
+/// # Safety
+///
+/// `mnemonic` must be a valid NUL-terminated string and `payment_id` must point to 8 readable
+/// bytes.
+///
+/// The returned string is owned by the caller and must be released with [`free_string`].
+#[no_mangle]
+pub unsafe extern "C" fn generate_integrated_address(
+    mnemonic: *const c_char,
+    network: u8,
+    payment_id: *const u8,
+) -> *mut c_char {
+    if mnemonic.is_null() || payment_id.is_null() {
+        return std::ptr::null_mut();
+    }
+    let mnemonic = match CStr::from_ptr(mnemonic).to_str() {
+        Ok(mnemonic) => mnemonic,
+        Err(_) => return std::ptr::null_mut(),
+    };
+    let network = match network_from_u8(network) {
+        Some(network) => network,
+        None => return std::ptr::null_mut(),
+    };
+    let mut id = [0u8; 8];
+    id.copy_from_slice(std::slice::from_raw_parts(payment_id, 8));
+
+    let address = MoneroWallet::new(mnemonic, network).and_then(|wallet| wallet.get_integrated_address(id));
+    match address.ok().and_then(|address| CString::new(address).ok()) {
+        Some(address) => address.into_raw(),
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Releases a string returned by one of this library's functions.
+///
+/// # Safety
+///
+/// `s` must be null or a pointer returned by this library that has not already been freed.
+#[no_mangle]
+pub unsafe extern "C" fn free_string(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+/// Parses an address and returns its network, type, public keys, and payment ID as JSON.
+///
+/// Returns a null pointer if the address is invalid.
+///
+/// # Safety
+///
+/// `addr` must be a valid NUL-terminated string.
+///
+/// The returned string is owned by the caller and must be released with [`free_string`].
+#[no_mangle]
+pub unsafe extern "C" fn address_parse(addr: *const c_char) -> *mut c_char {
+    if addr.is_null() {
+        return std::ptr::null_mut();
+    }
+    let parsed = CStr::from_ptr(addr).to_str().map_err(|e| e.to_string()).and_then(address::parse_address);
+    match parsed.ok().and_then(|parsed| CString::new(parsed.to_json().to_string()).ok()) {
+        Some(json) => json.into_raw(),
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Decodes a hex-encoded transaction and returns its summary as JSON.
+///
+/// Returns a null pointer if the transaction is invalid.
+///
+/// # Safety
+///
+/// `tx_hex` must be a valid NUL-terminated string.
+///
+/// The returned string is owned by the caller and must be released with [`free_string`].
+#[no_mangle]
+pub unsafe extern "C" fn decode_transaction(tx_hex: *const c_char) -> *mut c_char {
+    if tx_hex.is_null() {
+        return std::ptr::null_mut();
+    }
+    let info = CStr::from_ptr(tx_hex).to_str().map_err(|e| e.to_string()).and_then(tx::decode_transaction);
+    match info.ok().and_then(|info| CString::new(info.to_json().to_string()).ok()) {
+        Some(json) => json.into_raw(),
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Generates a `monero:` payment URI from a mnemonic for the given account and index.
+///
+/// An `amount` of 0 omits the amount, and a null `description` omits the description. Returns a
+/// null pointer if any argument is invalid.
+///
+/// # Safety
+///
+/// `mnemonic` must be a valid NUL-terminated string, and `description` must be null or a valid
+/// NUL-terminated string.
+///
+/// The returned string is owned by the caller and must be released with [`free_string`].
+#[no_mangle]
+pub unsafe extern "C" fn generate_payment_uri(
+    mnemonic: *const c_char,
+    network: u8,
+    account: u32,
+    index: u32,
+    amount: u64,
+    description: *const c_char,
+) -> *mut c_char {
+    if mnemonic.is_null() {
+        return std::ptr::null_mut();
+    }
+    let mnemonic = match CStr::from_ptr(mnemonic).to_str() {
+        Ok(mnemonic) => mnemonic,
+        Err(_) => return std::ptr::null_mut(),
+    };
+    let description = if description.is_null() {
+        None
+    } else {
+        match CStr::from_ptr(description).to_str() {
+            Ok(description) => Some(description),
+            Err(_) => return std::ptr::null_mut(),
+        }
+    };
+    let network = match network_from_u8(network) {
+        Some(network) => network,
+        None => return std::ptr::null_mut(),
+    };
+    let amount = (amount != 0).then_some(amount);
+
+    let uri = MoneroWallet::new(mnemonic, network).and_then(|wallet| wallet.get_payment_uri(account, index, amount, description));
+    match uri.ok().and_then(|uri| CString::new(uri).ok()) {
+        Some(uri) => uri.into_raw(),
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Formats an amount in atomic units as a decimal XMR string.
+///
+/// The returned string is owned by the caller and must be released with [`free_string`].
+#[no_mangle]
+pub extern "C" fn format_amount(atomic: u64) -> *mut c_char {
+    match CString::new(atomic_to_xmr_string(atomic)) {
+        Ok(amount) => amount.into_raw(),
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+/// Parses a decimal XMR string into atomic units, writing the result to `atomic`.
+///
+/// Returns false, leaving `atomic` untouched, if the amount is invalid.
+///
+/// # Safety
+///
+/// `amount` must be a valid NUL-terminated string and `atomic` must be a valid, writable pointer.
+#[no_mangle]
+pub unsafe extern "C" fn parse_amount(amount: *const c_char, atomic: *mut u64) -> bool {
+    if amount.is_null() || atomic.is_null() {
+        return false;
+    }
+    match CStr::from_ptr(amount).to_str().map_err(|e| e.to_string()).and_then(xmr_string_to_atomic) {
+        Ok(parsed) => {
+            *atomic = parsed;
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+/// Generates the primary address for a hex-encoded private spend key.
+///
+/// Returns a null pointer if any argument is invalid.
+///
+/// # Safety
+///
+/// `spend_key` must be a valid NUL-terminated string.
+///
+/// The returned string is owned by the caller and must be released with [`free_string`].
+#[no_mangle]
+pub unsafe extern "C" fn generate_address_from_spend_key(spend_key: *const c_char, network: u8) -> *mut c_char {
+    if spend_key.is_null() {
+        return std::ptr::null_mut();
+    }
+    let spend_key = match CStr::from_ptr(spend_key).to_str() {
+        Ok(spend_key) => spend_key,
+        Err(_) => return std::ptr::null_mut(),
+    };
+    let network = match network_from_u8(network) {
+        Some(network) => network,
+        None => return std::ptr::null_mut(),
+    };
+
+    let address = MoneroWallet::new_from_spend_key(spend_key, network).map(|wallet| wallet.get_primary_address());
+    match address.ok().and_then(|address| CString::new(address).ok()) {
+        Some(address) => address.into_raw(),
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Derives the public spend and view keys of a subaddress from a mnemonic, returned as JSON.
+///
+/// Returns a null pointer if any argument is invalid.
+///
+/// # Safety
+///
+/// `mnemonic` must be a valid NUL-terminated string.
+///
+/// The returned string is owned by the caller and must be released with [`free_string`].
+#[no_mangle]
+pub unsafe extern "C" fn generate_subaddress_keys(
+    mnemonic: *const c_char,
+    network: u8,
+    account: u32,
+    index: u32,
+) -> *mut c_char {
+    if mnemonic.is_null() {
+        return std::ptr::null_mut();
+    }
+    let mnemonic = match CStr::from_ptr(mnemonic).to_str() {
+        Ok(mnemonic) => mnemonic,
+        Err(_) => return std::ptr::null_mut(),
+    };
+    let network = match network_from_u8(network) {
+        Some(network) => network,
+        None => return std::ptr::null_mut(),
+    };
+
+    let keys = MoneroWallet::new(mnemonic, network).and_then(|wallet| wallet.get_subaddress_keys(account, index));
+    let json = keys.map(|(spend_public, view_public)| serde_json::json!({ "spend_public": spend_public, "view_public": view_public }));
+    match json.ok().and_then(|json| CString::new(json.to_string()).ok()) {
+        Some(json) => json.into_raw(),
+        None => std::ptr::null_mut(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -170,7 +686,7 @@ mod tests {
     fn test_wallet_creation() {
         let mnemonic = MoneroWallet::generate_mnemonic(Language::English);
         let wallet = MoneroWallet::new(&mnemonic, Network::Mainnet).expect("Failed to create wallet");
-        assert_eq!(wallet.get_seed(), mnemonic);
+        assert_eq!(wallet.get_seed(), Some(mnemonic));
         println!("Primary Address: {}", wallet.get_primary_address());
     }
 
@@ -190,6 +706,200 @@ mod tests {
         assert!(!subaddress.is_empty());
     }
 
+    #[test]
+    fn test_get_integrated_address() {
+        let mnemonic = MoneroWallet::generate_mnemonic(Language::English);
+        let wallet = MoneroWallet::new(&mnemonic, Network::Mainnet).expect("Failed to create wallet");
+        let payment_id = MoneroWallet::generate_random_payment_id();
+        let integrated = wallet.get_integrated_address(payment_id).expect("Failed to get integrated address");
+        let decoded = MoneroAddress::from_str(Network::Mainnet, &integrated).expect("Failed to parse integrated address");
+        assert_eq!(decoded.payment_id(), Some(payment_id));
+        assert!(!decoded.is_subaddress());
+        assert_eq!(decoded.spend, wallet.view_pair.spend());
+    }
+
+    #[test]
+    fn test_generate_integrated_address_ffi() {
+        let mnemonic = MoneroWallet::generate_mnemonic(Language::English);
+        let wallet = MoneroWallet::new(&mnemonic, Network::Stagenet).expect("Failed to create wallet");
+        let payment_id = [1u8, 2, 3, 4, 5, 6, 7, 8];
+        let c_mnemonic = CString::new(mnemonic).unwrap();
+        let ptr = unsafe { generate_integrated_address(c_mnemonic.as_ptr(), 2, payment_id.as_ptr()) };
+        assert!(!ptr.is_null());
+        let address = unsafe { CStr::from_ptr(ptr) }.to_str().unwrap().to_string();
+        unsafe { free_string(ptr) };
+        assert_eq!(address, wallet.get_integrated_address(payment_id).unwrap());
+        assert!(unsafe { generate_integrated_address(c_mnemonic.as_ptr(), 3, payment_id.as_ptr()) }.is_null());
+        unsafe { free_string(std::ptr::null_mut()) };
+    }
+
+    #[test]
+    fn test_address_parse_ffi() {
+        let address = CString::new("45wsWad9EwZgF3VpxQumrUCRaEtdyyh6NG8sVD3YRVVJbK1jkpJ3zq8WHLijVzodQ22LxwkdWx7fS2a6JzaRGzkNU8K2Dhi").unwrap();
+        let ptr = unsafe { address_parse(address.as_ptr()) };
+        assert!(!ptr.is_null());
+        let json: serde_json::Value = serde_json::from_str(unsafe { CStr::from_ptr(ptr) }.to_str().unwrap()).unwrap();
+        unsafe { free_string(ptr) };
+        assert_eq!(json["kind"], "legacy");
+        let invalid = CString::new("notanaddress").unwrap();
+        assert!(unsafe { address_parse(invalid.as_ptr()) }.is_null());
+    }
+
+    #[test]
+    fn test_passphrase() {
+        let mnemonic = MoneroWallet::generate_mnemonic(Language::English);
+        let wallet = MoneroWallet::new(&mnemonic, Network::Mainnet).expect("Failed to create wallet");
+        let empty = MoneroWallet::new_with_passphrase(&mnemonic, "", Network::Mainnet).expect("Failed to create wallet");
+        assert!(!empty.has_passphrase());
+        assert_eq!(empty.get_primary_address(), wallet.get_primary_address());
+        assert_eq!(empty.get_private_spend_key(), wallet.get_private_spend_key());
+
+        let first = MoneroWallet::new_with_passphrase(&mnemonic, "first", Network::Mainnet).expect("Failed to create wallet");
+        let second = MoneroWallet::new_with_passphrase(&mnemonic, "second", Network::Mainnet).expect("Failed to create wallet");
+        assert!(first.has_passphrase());
+        assert_eq!(first.get_seed(), Some(mnemonic.clone()));
+        assert_ne!(first.get_primary_address(), wallet.get_primary_address());
+        assert_ne!(first.get_primary_address(), second.get_primary_address());
+        assert_ne!(first.get_private_view_key(), wallet.get_private_view_key());
+
+        // Computed with Monero's cn_slow_hash and sc_sub, as cryptonote::decrypt_key applies them.
+        let mnemonic = "hemlock jubilee eden hacksaw boil superior inroads epoxy exhale orders cavernous second brunt saved richly lower upgrade hitched launching deepest mostly playful layout lower eden";
+        let wallet = MoneroWallet::new_with_passphrase(mnemonic, "correct horse battery staple", Network::Mainnet).expect("Failed to create wallet");
+        assert_eq!(wallet.get_private_spend_key(), "b0c1a1ee4fd2e126c71d93f6794896c2ccd16d617f73a519394c265686905804");
+    }
+
+    #[test]
+    fn test_network_strings() {
+        for network in [Network::Mainnet, Network::Testnet, Network::Stagenet] {
+            assert_eq!(network_from_str(network_to_str(network)), Ok(network));
+        }
+        assert_eq!(network_from_str("Stagenet"), Ok(Network::Stagenet));
+        assert!(network_from_str("regtest").is_err());
+    }
+
+    #[test]
+    fn test_detect_network_from_address() {
+        let mnemonic = MoneroWallet::generate_mnemonic(Language::English);
+        // Testnet's standard prefix byte (53) encodes to either leading character.
+        let prefixes = [(Network::Mainnet, ["4", "4", "8"]), (Network::Testnet, ["9A", "A", "B"]), (Network::Stagenet, ["5", "5", "7"])];
+        for (network, [standard, integrated, subaddress]) in prefixes {
+            let wallet = MoneroWallet::new(&mnemonic, network).expect("Failed to create wallet");
+            let addresses = [
+                (wallet.get_primary_address(), standard),
+                (wallet.get_integrated_address([7; 8]).unwrap(), integrated),
+                (wallet.get_subaddress(0, 1).unwrap(), subaddress),
+            ];
+            for (address, prefix) in addresses {
+                assert!(address.starts_with(|c| prefix.contains(c)), "{} should start with one of {}", address, prefix);
+                assert_eq!(detect_network_from_address(&address), Some(network));
+            }
+        }
+        assert_eq!(detect_network_from_address("not an address"), None);
+    }
+
+    #[test]
+    fn test_get_payment_uri() {
+        let mnemonic = MoneroWallet::generate_mnemonic(Language::English);
+        let wallet = MoneroWallet::new(&mnemonic, Network::Mainnet).expect("Failed to create wallet");
+        let uri = wallet.get_payment_uri(0, 1, Some(1_500_000_000_000), Some("Order 7")).expect("Failed to get payment URI");
+        let payment = uri::parse_payment_uri(&uri).expect("Failed to parse payment URI");
+        assert_eq!(payment.address, wallet.get_subaddress(0, 1).unwrap());
+        assert_eq!(payment.amount, Some(1_500_000_000_000));
+        assert_eq!(payment.description.as_deref(), Some("Order 7"));
+
+        let uri = wallet.get_payment_uri(0, 0, None, None).expect("Failed to get payment URI");
+        assert_eq!(uri, format!("monero:{}", wallet.get_primary_address()));
+    }
+
+    #[test]
+    fn test_amount_formatting() {
+        assert_eq!(atomic_to_xmr_string(0), "0");
+        assert_eq!(atomic_to_xmr_string(1), "0.000000000001");
+        assert_eq!(atomic_to_xmr_string(2_000_000_000_000), "2");
+        assert_eq!(atomic_to_xmr_string(u64::MAX), "18446744.073709551615");
+
+        assert_eq!(xmr_string_to_atomic("0.000000000001"), Ok(1));
+        assert_eq!(xmr_string_to_atomic("1.5"), Ok(1_500_000_000_000));
+        assert_eq!(xmr_string_to_atomic(".5"), Ok(500_000_000_000));
+        assert_eq!(xmr_string_to_atomic("18446744.073709551615"), Ok(u64::MAX));
+        assert!(xmr_string_to_atomic("18446744.073709551616").is_err());
+        assert!(xmr_string_to_atomic("0.0000000000001").is_err());
+        assert!(xmr_string_to_atomic("-1").is_err());
+        assert!(xmr_string_to_atomic("1e3").is_err());
+        assert!(xmr_string_to_atomic(".").is_err());
+        assert!(xmr_string_to_atomic("").is_err());
+    }
+
+    #[test]
+    fn test_parse_amount_ffi() {
+        let mut atomic = 0u64;
+        let amount = CString::new("0.25").unwrap();
+        assert!(unsafe { parse_amount(amount.as_ptr(), &mut atomic) });
+        assert_eq!(atomic, 250_000_000_000);
+        let amount = CString::new("-0.25").unwrap();
+        assert!(!unsafe { parse_amount(amount.as_ptr(), &mut atomic) });
+        assert_eq!(atomic, 250_000_000_000);
+    }
+
+    #[test]
+    fn test_new_from_spend_key() {
+        // https://xmrtests.llcoins.net/addresstests.html
+        let mnemonic = "hemlock jubilee eden hacksaw boil superior inroads epoxy exhale orders cavernous second brunt saved richly lower upgrade hitched launching deepest mostly playful layout lower eden";
+        let spend_key = "29adefc8f67515b4b4bf48031780ab9d071d24f8a674b879ce7f245c37523807";
+        let from_mnemonic = MoneroWallet::new(mnemonic, Network::Mainnet).expect("Failed to create wallet");
+        let wallet = MoneroWallet::new_from_spend_key(spend_key, Network::Mainnet).expect("Failed to create wallet");
+        assert_eq!(wallet.get_seed(), None);
+        assert_eq!(wallet.get_primary_address(), "45wsWad9EwZgF3VpxQumrUCRaEtdyyh6NG8sVD3YRVVJbK1jkpJ3zq8WHLijVzodQ22LxwkdWx7fS2a6JzaRGzkNU8K2Dhi");
+        assert_eq!(wallet.get_primary_address(), from_mnemonic.get_primary_address());
+        assert_eq!(wallet.get_subaddress(0, 1), from_mnemonic.get_subaddress(0, 1));
+        assert_eq!(wallet.get_private_spend_key(), spend_key);
+        assert_eq!(wallet.get_private_view_key(), from_mnemonic.get_private_view_key());
+        assert_eq!(wallet.get_public_view_key(), "21243cb8d0046baf10619d1fe7f38708095b006ef8e8350963c160478c1c0ff0");
+
+        assert!(MoneroWallet::new_from_spend_key(&spend_key[.. 62], Network::Mainnet).is_err());
+        assert!(MoneroWallet::new_from_spend_key(&"00".repeat(32), Network::Mainnet).is_err());
+        assert!(MoneroWallet::new_from_spend_key(&"ff".repeat(32), Network::Mainnet).is_err());
+    }
+
+    #[test]
+    fn test_get_subaddress_keys() {
+        // Subaddress (0, 1) vectors from https://xmrtests.llcoins.net/addresstests.html and
+        // https://monero.stackexchange.com/a/8767. The (1, 0) keys were derived with Monero's C
+        // crypto-ops (cn_fast_hash, ge_scalarmult_base, ge_add, ge_scalarmult), which reproduce
+        // both published (0, 1) addresses.
+        let vectors = [
+            (
+                "hemlock jubilee eden hacksaw boil superior inroads epoxy exhale orders cavernous second brunt saved richly lower upgrade hitched launching deepest mostly playful layout lower eden",
+                Network::Mainnet,
+                "86QMPxju4EHGHZfyswVHXsQcKK3vJgqUFgbP8Xx8DNTSjaGqcp8KXc9isQS3Hh8twz8huegagK19rJLDbBwCwAxRHX4vcv5",
+                (
+                    "975e820c8d20a9c91c8efb3579c95d1c5c80204e3a2d28e1d2a84036e3b7c57c",
+                    "ff8078aac7221074b7aa71cc4f5183739bd8075738c0fa0f9a8150e342637ff3",
+                ),
+            ),
+            (
+                "honked bagpipe alpine juicy faked afoot jostle claim cowl tunnel orphans negative pheasants feast jetting quote frown teeming cycling tribal womanly hills cottage daytime daytime",
+                Network::Stagenet,
+                "75fvgGHHE1aAqex6Pq51hu9vG4GJd9zbsRKHxZymPa9xNPwNkK5g16idhG1Qn8C9eGdAGPXZ4E8Cz1gsotu3AynFVFBGca6",
+                (
+                    "276da7a74c53ef59c9d0a1896a3ac4bb5eb2ad4cb4d87d5f7a33bac912764917",
+                    "6f5299fd841b63b3cf2065003678dc72b45218f5e8549b885ed1d824489dd9a0",
+                ),
+            ),
+        ];
+        for (mnemonic, network, subaddress, (spend_public, view_public)) in vectors {
+            let wallet = MoneroWallet::new(mnemonic, network).expect("Failed to create wallet");
+            let parsed = address::parse_address(subaddress).expect("Failed to parse subaddress");
+            let keys = wallet.get_subaddress_keys(0, 1).expect("Failed to get subaddress keys");
+            assert_eq!(keys, (hex::encode(parsed.spend_public), hex::encode(parsed.view_public)));
+
+            let keys = wallet.get_subaddress_keys(1, 0).expect("Failed to get subaddress keys");
+            assert_eq!(keys, (spend_public.to_string(), view_public.to_string()));
+        }
+        let wallet = MoneroWallet::new(vectors[0].0, Network::Mainnet).unwrap();
+        assert!(wallet.get_subaddress_keys(0, 0).is_err());
+    }
+
     #[test]
     fn test_keys() {
         let mnemonic = MoneroWallet::generate_mnemonic(Language::English);
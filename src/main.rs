@@ -29,8 +29,6 @@ use zeroize::Zeroizing;
 
 use std::collections::HashSet;
 
-use tokio; // for async main
-
 #[tokio::main]
 async fn main() {
     // address generation test vectors
@@ -88,7 +86,7 @@ fn digest_mnemonic(mnemonic: &str, network: &Network) {
     println!("Private spend key: {:?}", hex::encode(spend));
     let spend_scalar = Scalar::from_bytes_mod_order(spend);
     let spend_point: EdwardsPoint = &spend_scalar * &ED25519_BASEPOINT_TABLE;
-    let view: [u8; 32] = Keccak256::digest(&spend).into();
+    let view: [u8; 32] = Keccak256::digest(spend).into();
     let view_scalar = Scalar::from_bytes_mod_order(view);
     println!("Private view key: {:?}", hex::encode(view_scalar.to_bytes()));
     let view_point: EdwardsPoint = &view_scalar * &ED25519_BASEPOINT_TABLE;
@@ -120,7 +118,7 @@ async fn test_output_detection() {
     let spend: [u8; 32] = *seed.entropy();
     let spend_scalar = Scalar::from_bytes_mod_order(spend);
     let spend_point: EdwardsPoint = &spend_scalar * &ED25519_BASEPOINT_TABLE;
-    let view: [u8; 32] = Keccak256::digest(&spend).into();
+    let view: [u8; 32] = Keccak256::digest(spend).into();
     let view_scalar = Scalar::from_bytes_mod_order(view);
     // let view_point: EdwardsPoint = &view_scalar * &ED25519_BASEPOINT_TABLE;
     // let address = MoneroAddress::new(
@@ -0,0 +1,314 @@
+use monero_serai_mirror::{
+    ringct::RctBase,
+    transaction::{Input, Timelock, Transaction, TransactionPrefix},
+    wallet::extra::{Extra, PaymentId},
+};
+use sha3::{Digest, Keccak256};
+
+/// A summary of a serialized Monero transaction.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TxInfo {
+    pub version: u64,
+    /// Whether the blob omits the prunable RingCT data (bulletproofs, CLSAGs, pseudo-outs).
+    pub pruned: bool,
+    /// The transaction hash, only computable from a full (unpruned) transaction.
+    pub hash: Option<[u8; 32]>,
+    pub is_coinbase: bool,
+    pub unlock_time: u64,
+    pub input_count: usize,
+    pub output_count: usize,
+    /// The ring size of the first non-coinbase input.
+    pub ring_size: Option<usize>,
+    /// The fee, if the transaction is not a coinbase.
+    pub fee: Option<u64>,
+    /// The RingCT type byte, for version 2 transactions.
+    pub rct_type: Option<u8>,
+    pub tx_public_key: Option<[u8; 32]>,
+    pub additional_public_keys: Vec<[u8; 32]>,
+    /// The payment ID carried in the extra nonce, encrypted (8 bytes) or legacy (32 bytes).
+    pub payment_id: Option<Vec<u8>>,
+    /// The raw extra field.
+    pub extra: Vec<u8>,
+}
+
+impl TxInfo {
+    /// Serializes the transaction summary as a JSON object with hex-encoded byte fields.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "version": self.version,
+            "pruned": self.pruned,
+            "hash": self.hash.map(hex::encode),
+            "is_coinbase": self.is_coinbase,
+            "unlock_time": self.unlock_time,
+            "input_count": self.input_count,
+            "output_count": self.output_count,
+            "ring_size": self.ring_size,
+            "fee": self.fee,
+            "rct_type": self.rct_type,
+            "tx_public_key": self.tx_public_key.map(hex::encode),
+            "additional_public_keys": self.additional_public_keys.iter().map(hex::encode).collect::<Vec<_>>(),
+            "payment_id": self.payment_id.as_ref().map(hex::encode),
+            "extra": hex::encode(&self.extra),
+        })
+    }
+}
+
+/// Decodes a hex-encoded transaction blob, full or pruned, into a [`TxInfo`] summary.
+///
+/// # Arguments
+///
+/// * `tx_hex` - The hex-encoded transaction, as produced by a wallet or returned by a daemon.
+///
+/// # Errors
+///
+/// Returns an error if the input is not hex or not a valid transaction, or if it is a version 2
+/// transaction whose RingCT type is not null (0), CLSAG (5), or Bulletproof+ (6). Transactions
+/// using the older RingCT types 1 to 4, from before the 2020 CLSAG upgrade, are not supported.
+pub fn decode_transaction(tx_hex: &str) -> Result<TxInfo, String> {
+    let blob = hex::decode(tx_hex.trim()).map_err(|_| "Invalid hex".to_string())?;
+
+    let mut reader = blob.as_slice();
+    let prefix = TransactionPrefix::read(&mut reader).map_err(|e| e.to_string())?;
+    let (fee, rct_type, hash, pruned) = match prefix.version {
+        1 => {
+            // Version 1 carries a signature pair per ring member, with no prunable section.
+            let ring_members: usize = prefix.inputs.iter().map(|input| match input {
+                Input::ToKey { key_offsets, .. } => key_offsets.len(),
+                Input::Gen(_) => 0,
+            }).sum();
+            if reader.len() != ring_members * 64 {
+                return Err("Invalid transaction".to_string());
+            }
+            let inputs = prefix.inputs.iter().try_fold(0u64, |sum, input| match input {
+                Input::ToKey { amount, .. } => sum.checked_add(*amount),
+                Input::Gen(_) => Some(sum),
+            });
+            let outputs = prefix.outputs.iter().try_fold(0u64, |sum, output| sum.checked_add(output.amount));
+            let fee = match (inputs, outputs) {
+                // A coinbase mints its outputs, so only its sum needs to be valid.
+                (Some(_), Some(_)) if matches!(prefix.inputs.first(), Some(Input::Gen(_))) => 0,
+                (Some(inputs), Some(outputs)) => inputs.checked_sub(outputs).ok_or("Invalid transaction".to_string())?,
+                _ => return Err("Invalid transaction".to_string()),
+            };
+            (fee, None, Some(Keccak256::digest(&blob).into()), false)
+        }
+        2 => {
+            let mut full = blob.as_slice();
+            match Transaction::read(&mut full) {
+                Ok(tx) if full.is_empty() => {
+                    let rct_type = tx.rct_signatures.prunable.rct_type();
+                    (tx.rct_signatures.base.fee, Some(rct_type), Some(tx.hash()), false)
+                }
+                _ => {
+                    // Only the null, CLSAG, and Bulletproof+ types are understood upstream. Older
+                    // types also encode their base differently, so reject them before reading it.
+                    match reader.first() {
+                        Some(0 | 5 | 6) | None => {}
+                        Some(rct_type) => return Err(format!("Unsupported RingCT type {}", rct_type)),
+                    }
+                    // A pruned transaction is the prefix followed by only the RingCT base.
+                    let (base, rct_type): (RctBase, u8) =
+                        RctBase::read(prefix.outputs.len(), &mut reader).map_err(|e| e.to_string())?;
+                    if !reader.is_empty() {
+                        return Err("Invalid transaction".to_string());
+                    }
+                    (base.fee, Some(rct_type), None, true)
+                }
+            }
+        }
+        _ => return Err("Unsupported transaction version".to_string()),
+    };
+
+    let is_coinbase = matches!(prefix.inputs.first(), Some(Input::Gen(_)));
+    let ring_size = prefix.inputs.iter().find_map(|input| match input {
+        Input::ToKey { key_offsets, .. } => Some(key_offsets.len()),
+        Input::Gen(_) => None,
+    });
+    let unlock_time = match prefix.timelock {
+        Timelock::None => 0,
+        Timelock::Block(height) => height as u64,
+        Timelock::Time(time) => time,
+    };
+
+    let extra = Extra::read(&mut prefix.extra.as_slice()).map_err(|e| e.to_string())?;
+    let (tx_public_key, additional_public_keys) = match extra.keys() {
+        Some((key, additional)) => (
+            Some(key.compress().to_bytes()),
+            additional.unwrap_or_default().iter().map(|key| key.compress().to_bytes()).collect(),
+        ),
+        None => (None, vec![]),
+    };
+    let payment_id = extra.payment_id().map(|id| match id {
+        PaymentId::Encrypted(id) => id.to_vec(),
+        PaymentId::Unencrypted(id) => id.to_vec(),
+    });
+
+    Ok(TxInfo {
+        version: prefix.version,
+        pruned,
+        hash,
+        is_coinbase,
+        unlock_time,
+        input_count: prefix.inputs.len(),
+        output_count: prefix.outputs.len(),
+        ring_size,
+        fee: (!is_coinbase).then_some(fee),
+        rct_type,
+        tx_public_key,
+        additional_public_keys,
+        payment_id,
+        extra: prefix.extra,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use curve25519_dalek::{constants::ED25519_BASEPOINT_POINT, scalar::Scalar};
+    use monero_serai_mirror::{ringct::{RctPrunable, RctSignatures}, transaction::Output};
+
+    fn extra() -> Vec<u8> {
+        // tx public key, then a nonce holding an encrypted payment ID
+        let mut extra = vec![1];
+        extra.extend(ED25519_BASEPOINT_POINT.compress().to_bytes());
+        extra.extend([2, 9, 1, 1, 2, 3, 4, 5, 6, 7, 8]);
+        extra
+    }
+
+    fn output(amount: u64) -> Output {
+        Output { amount, key: ED25519_BASEPOINT_POINT.compress(), view_tag: None }
+    }
+
+    #[test]
+    fn test_decode_v1_transaction() {
+        let tx = Transaction {
+            prefix: TransactionPrefix {
+                version: 1,
+                timelock: Timelock::None,
+                inputs: vec![Input::ToKey {
+                    amount: 1000,
+                    key_offsets: vec![1, 2, 3, 4],
+                    key_image: ED25519_BASEPOINT_POINT,
+                }],
+                outputs: vec![output(600), output(300)],
+                extra: extra(),
+            },
+            // One signature pair per ring member
+            signatures: vec![(Scalar::one(), Scalar::one()); 4],
+            rct_signatures: RctSignatures {
+                base: RctBase { fee: 0, ecdh_info: vec![], commitments: vec![] },
+                prunable: RctPrunable::Null,
+            },
+        };
+        let info = decode_transaction(&hex::encode(tx.serialize())).expect("Failed to decode transaction");
+        assert_eq!(info.version, 1);
+        assert!(!info.pruned);
+        assert_eq!(info.hash, Some(tx.hash()));
+        assert!(!info.is_coinbase);
+        assert_eq!((info.input_count, info.output_count), (1, 2));
+        assert_eq!(info.ring_size, Some(4));
+        assert_eq!(info.fee, Some(100));
+        assert_eq!(info.rct_type, None);
+        assert_eq!(info.tx_public_key, Some(ED25519_BASEPOINT_POINT.compress().to_bytes()));
+        assert_eq!(info.payment_id, Some(vec![1, 2, 3, 4, 5, 6, 7, 8]));
+    }
+
+    #[test]
+    fn test_decode_v1_invalid_amounts() {
+        let v1 = |amounts: &[u64], outputs: Vec<Output>| {
+            Transaction {
+                prefix: TransactionPrefix {
+                    version: 1,
+                    timelock: Timelock::None,
+                    inputs: amounts
+                        .iter()
+                        .map(|amount| Input::ToKey { amount: *amount, key_offsets: vec![1], key_image: ED25519_BASEPOINT_POINT })
+                        .collect(),
+                    outputs,
+                    extra: extra(),
+                },
+                signatures: vec![(Scalar::one(), Scalar::one()); amounts.len()],
+                rct_signatures: RctSignatures {
+                    base: RctBase { fee: 0, ecdh_info: vec![], commitments: vec![] },
+                    prunable: RctPrunable::Null,
+                },
+            }
+            .serialize()
+        };
+        // Input amounts that overflow a u64
+        assert!(decode_transaction(&hex::encode(v1(&[1 << 63, 1 << 63], vec![output(1)]))).is_err());
+        // Outputs exceeding inputs
+        assert!(decode_transaction(&hex::encode(v1(&[1000], vec![output(600), output(600)]))).is_err());
+        assert!(decode_transaction(&hex::encode(v1(&[1000], vec![output(600), output(400)]))).is_ok());
+    }
+
+    #[test]
+    fn test_decode_v2_coinbase() {
+        let mut blob = TransactionPrefix {
+            version: 2,
+            timelock: Timelock::Block(60),
+            inputs: vec![Input::Gen(1000)],
+            outputs: vec![output(17)],
+            extra: extra(),
+        }
+        .serialize();
+        blob.push(0);
+        let info = decode_transaction(&hex::encode(blob)).expect("Failed to decode transaction");
+        assert!(info.is_coinbase);
+        assert!(!info.pruned);
+        assert_eq!(info.unlock_time, 60);
+        assert_eq!(info.fee, None);
+        assert_eq!(info.rct_type, Some(0));
+        assert_eq!(info.ring_size, None);
+    }
+
+    #[test]
+    fn test_decode_pruned_transaction() {
+        let mut blob = TransactionPrefix {
+            version: 2,
+            timelock: Timelock::None,
+            inputs: vec![Input::ToKey {
+                amount: 0,
+                key_offsets: vec![1; 16],
+                key_image: ED25519_BASEPOINT_POINT,
+            }],
+            outputs: vec![output(0), output(0)],
+            extra: extra(),
+        }
+        .serialize();
+        // RingCT base: type 6 (bulletproof+), fee, then ecdh info and commitments per output
+        blob.extend([6, 0x64]);
+        blob.extend([0; 16]);
+        blob.extend(ED25519_BASEPOINT_POINT.compress().to_bytes());
+        blob.extend(ED25519_BASEPOINT_POINT.compress().to_bytes());
+        let info = decode_transaction(&hex::encode(blob)).expect("Failed to decode transaction");
+        assert!(info.pruned);
+        assert_eq!(info.hash, None);
+        assert_eq!(info.ring_size, Some(16));
+        assert_eq!(info.fee, Some(100));
+        assert_eq!(info.rct_type, Some(6));
+    }
+
+    #[test]
+    fn test_decode_unsupported_rct_type() {
+        let mut blob = TransactionPrefix {
+            version: 2,
+            timelock: Timelock::None,
+            inputs: vec![Input::ToKey { amount: 0, key_offsets: vec![1; 11], key_image: ED25519_BASEPOINT_POINT }],
+            outputs: vec![output(0)],
+            extra: extra(),
+        }
+        .serialize();
+        // RingCT base: type 4 (Bulletproof), fee, then the prunable data that follows in a full blob
+        blob.extend([4, 0x64]);
+        blob.extend([0; 96]);
+        assert_eq!(decode_transaction(&hex::encode(blob)), Err("Unsupported RingCT type 4".to_string()));
+    }
+
+    #[test]
+    fn test_decode_invalid_transaction() {
+        assert!(decode_transaction("zz").is_err());
+        assert!(decode_transaction("").is_err());
+        assert!(decode_transaction("02000102").is_err());
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{
+    address::{parse_address, AddressKind},
+    atomic_to_xmr_string, xmr_string_to_atomic,
+};
+
+/// A decoded `monero:` payment URI.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PaymentUri {
+    pub address: String,
+    /// The payment ID to attach, short (8 bytes) or legacy long (32 bytes).
+    pub payment_id: Option<Vec<u8>>,
+    /// The requested amount in atomic units.
+    pub amount: Option<u64>,
+    pub description: Option<String>,
+    pub recipient_name: Option<String>,
+}
+
+impl PaymentUri {
+    /// Encodes the payment request as a `monero:` URI, percent-encoding the text fields.
+    pub fn to_uri(&self) -> String {
+        let mut params = vec![];
+        if let Some(payment_id) = &self.payment_id {
+            params.push(format!("tx_payment_id={}", hex::encode(payment_id)));
+        }
+        if let Some(amount) = self.amount {
+            params.push(format!("tx_amount={}", atomic_to_xmr_string(amount)));
+        }
+        if let Some(description) = &self.description {
+            params.push(format!("tx_description={}", percent_encode(description)));
+        }
+        if let Some(recipient_name) = &self.recipient_name {
+            params.push(format!("recipient_name={}", percent_encode(recipient_name)));
+        }
+
+        let mut uri = format!("monero:{}", self.address);
+        if !params.is_empty() {
+            uri.push('?');
+            uri.push_str(&params.join("&"));
+        }
+        uri
+    }
+}
+
+/// Parses a `monero:` payment URI.
+///
+/// # Arguments
+///
+/// * `uri` - The URI, e.g. `monero:<address>?tx_amount=1.5&tx_description=Invoice%2042`.
+///
+/// # Errors
+///
+/// Returns an error if the scheme, address, payment ID, amount, or percent-encoding is invalid,
+/// if a parameter is repeated, or if a payment ID accompanies an integrated address.
+pub fn parse_payment_uri(uri: &str) -> Result<PaymentUri, String> {
+    let rest = uri.trim().strip_prefix("monero:").ok_or("Invalid URI scheme".to_string())?;
+    let (address, query) = match rest.split_once('?') {
+        Some((address, query)) => (address, Some(query)),
+        None => (rest, None),
+    };
+    let parsed = parse_address(address)?;
+
+    let mut payment = PaymentUri {
+        address: address.to_string(),
+        payment_id: None,
+        amount: None,
+        description: None,
+        recipient_name: None,
+    };
+    for param in query.into_iter().flat_map(|query| query.split('&')).filter(|param| !param.is_empty()) {
+        let (key, value) = param.split_once('=').unwrap_or((param, ""));
+        let duplicate = match key {
+            "tx_payment_id" => payment.payment_id.replace(parse_payment_id(value)?).is_some(),
+            "tx_amount" => payment.amount.replace(xmr_string_to_atomic(value)?).is_some(),
+            "tx_description" => payment.description.replace(percent_decode(value)?).is_some(),
+            "recipient_name" => payment.recipient_name.replace(percent_decode(value)?).is_some(),
+            // Unknown parameters are ignored.
+            _ => false,
+        };
+        if duplicate {
+            return Err(format!("Duplicate URI parameter: {}", key));
+        }
+    }
+    // An integrated address already carries a payment ID, so a second one is ambiguous.
+    if payment.payment_id.is_some() && parsed.kind == AddressKind::Integrated {
+        return Err("A payment ID cannot accompany an integrated address".to_string());
+    }
+    Ok(payment)
+}
+
+fn parse_payment_id(s: &str) -> Result<Vec<u8>, String> {
+    match hex::decode(s) {
+        Ok(payment_id) if payment_id.len() == 8 || payment_id.len() == 32 => Ok(payment_id),
+        _ => Err("Invalid payment ID".to_string()),
+    }
+}
+
+fn percent_encode(s: &str) -> String {
+    let mut encoded = String::with_capacity(s.len());
+    for byte in s.bytes() {
+        match byte {
+            b'A' ..= b'Z' | b'a' ..= b'z' | b'0' ..= b'9' | b'-' | b'_' | b'.' | b'~' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+fn percent_decode(s: &str) -> Result<String, String> {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                // from_str_radix alone would accept a sign, as in `%+A`.
+                let hex = match bytes.get(i + 1 .. i + 3) {
+                    Some(hex) if hex.iter().all(u8::is_ascii_hexdigit) => &s[i + 1 .. i + 3],
+                    _ => return Err("Invalid percent-encoding".to_string()),
+                };
+                decoded.push(u8::from_str_radix(hex, 16).map_err(|_| "Invalid percent-encoding".to_string())?);
+                i += 3;
+            }
+            b'+' => {
+                decoded.push(b' ');
+                i += 1;
+            }
+            byte => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8(decoded).map_err(|_| "Invalid UTF-8 in URI".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MoneroWallet, Network};
+
+    const MNEMONIC: &str = "hemlock jubilee eden hacksaw boil superior inroads epoxy exhale orders cavernous second brunt saved richly lower upgrade hitched launching deepest mostly playful layout lower eden";
+    const ADDRESS: &str = "45wsWad9EwZgF3VpxQumrUCRaEtdyyh6NG8sVD3YRVVJbK1jkpJ3zq8WHLijVzodQ22LxwkdWx7fS2a6JzaRGzkNU8K2Dhi";
+
+    #[test]
+    fn test_payment_uri_round_trip() {
+        let payment = PaymentUri {
+            address: ADDRESS.to_string(),
+            payment_id: None,
+            amount: Some(1_500_000_000_000),
+            description: Some("Invoice #42 & co".to_string()),
+            recipient_name: None,
+        };
+        let uri = payment.to_uri();
+        assert_eq!(uri, format!("monero:{}?tx_amount=1.5&tx_description=Invoice%20%2342%20%26%20co", ADDRESS));
+        assert_eq!(parse_payment_uri(&uri), Ok(payment));
+    }
+
+    #[test]
+    fn test_parse_payment_uri() {
+        let payment = parse_payment_uri(&format!("monero:{}", ADDRESS)).expect("Failed to parse URI");
+        assert_eq!(payment.amount, None);
+        let payment = parse_payment_uri(&format!("monero:{}?tx_amount=0.000000000001&recipient_name=Bob+Smith", ADDRESS)).expect("Failed to parse URI");
+        assert_eq!(payment.amount, Some(1));
+        assert_eq!(payment.recipient_name.as_deref(), Some("Bob Smith"));
+
+        assert!(parse_payment_uri(&format!("bitcoin:{}", ADDRESS)).is_err());
+        assert!(parse_payment_uri("monero:notanaddress").is_err());
+        assert!(parse_payment_uri(&format!("monero:{}?tx_amount=1.0000000000001", ADDRESS)).is_err());
+        assert!(parse_payment_uri(&format!("monero:{}?tx_amount=1&tx_amount=2", ADDRESS)).is_err());
+        assert!(parse_payment_uri(&format!("monero:{}?tx_description=%zz", ADDRESS)).is_err());
+        assert!(parse_payment_uri(&format!("monero:{}?tx_description=%+A", ADDRESS)).is_err());
+        assert!(parse_payment_uri(&format!("monero:{}?tx_description=%A", ADDRESS)).is_err());
+    }
+
+    #[test]
+    fn test_parse_payment_id() {
+        let payment = parse_payment_uri(&format!("monero:{}?tx_payment_id=0123456789abcdef&tx_amount=2", ADDRESS)).expect("Failed to parse URI");
+        assert_eq!(payment.payment_id, Some(hex::decode("0123456789abcdef").unwrap()));
+        assert_eq!(parse_payment_uri(&payment.to_uri()), Ok(payment));
+        let long_id = "ab".repeat(32);
+        let payment = parse_payment_uri(&format!("monero:{}?tx_payment_id={}", ADDRESS, long_id)).expect("Failed to parse URI");
+        assert_eq!(payment.payment_id.map(hex::encode), Some(long_id));
+
+        assert!(parse_payment_uri(&format!("monero:{}?tx_payment_id=0123", ADDRESS)).is_err());
+        assert!(parse_payment_uri(&format!("monero:{}?tx_payment_id=zz23456789abcdef", ADDRESS)).is_err());
+
+        let wallet = MoneroWallet::new(MNEMONIC, Network::Mainnet).expect("Failed to create wallet");
+        let integrated = wallet.get_integrated_address([1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+        assert!(parse_payment_uri(&format!("monero:{}", integrated)).is_ok());
+        assert!(parse_payment_uri(&format!("monero:{}?tx_payment_id=0123456789abcdef", integrated)).is_err());
+    }
+}
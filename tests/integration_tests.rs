@@ -4,7 +4,7 @@ use monero_rust::{MoneroWallet, Language, Network};
 fn test_integration_wallet_creation() {
     let mnemonic = MoneroWallet::generate_mnemonic(Language::English);
     let wallet = MoneroWallet::new(&mnemonic, Network::Mainnet).expect("Failed to create wallet");
-    assert_eq!(wallet.get_seed(), mnemonic);
+    assert_eq!(wallet.get_seed(), Some(mnemonic));
 }
 
 #[test]